@@ -0,0 +1,121 @@
+use std::time::Duration;
+
+use moka::future::Cache;
+
+use crate::config::CacheConfig;
+
+/// Caches the result of an external lookup (social-name resolver or on-chain
+/// verifier call) keyed by an arbitrary caller-built string, e.g.
+/// `"{field}:{verifier_contract}:{id}"`.
+///
+/// Negative results (`None`) are cached too, but under a much shorter TTL
+/// than positive ones, so a freshly-set record doesn't stay hidden for long
+/// while we still avoid hammering Discord/GitHub/Twitter/the RPC on repeat
+/// misses.
+#[derive(Clone)]
+pub struct LookupCache {
+    positive: Cache<String, Option<String>>,
+    negative: Cache<String, Option<String>>,
+}
+
+impl LookupCache {
+    pub fn new(config: &CacheConfig) -> Self {
+        LookupCache {
+            positive: Cache::builder()
+                .max_capacity(config.max_capacity)
+                .time_to_live(Duration::from_secs(config.positive_ttl_seconds))
+                .build(),
+            negative: Cache::builder()
+                .max_capacity(config.max_capacity)
+                .time_to_live(Duration::from_secs(config.negative_ttl_seconds))
+                .build(),
+        }
+    }
+
+    pub async fn get(&self, key: &str) -> Option<Option<String>> {
+        if let Some(hit) = self.positive.get(key).await {
+            return Some(hit);
+        }
+        self.negative.get(key).await
+    }
+
+    pub async fn insert(&self, key: String, value: Option<String>) {
+        match &value {
+            Some(_) => {
+                self.negative.invalidate(&key).await;
+                self.positive.insert(key, value).await;
+            }
+            None => {
+                self.positive.invalidate(&key).await;
+                self.negative.insert(key, value).await;
+            }
+        }
+    }
+
+    /// Drops any cached entry for `key`, forcing the next lookup to refresh it.
+    pub async fn invalidate(&self, key: &str) {
+        self.positive.invalidate(key).await;
+        self.negative.invalidate(key).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config() -> CacheConfig {
+        CacheConfig {
+            positive_ttl_seconds: 300,
+            negative_ttl_seconds: 30,
+            max_capacity: 100,
+        }
+    }
+
+    #[tokio::test]
+    async fn caches_positive_and_negative_results() {
+        let cache = LookupCache::new(&test_config());
+
+        cache.insert("hit".to_string(), Some("alice".to_string())).await;
+        cache.insert("miss".to_string(), None).await;
+
+        assert_eq!(cache.get("hit").await, Some(Some("alice".to_string())));
+        assert_eq!(cache.get("miss").await, Some(None));
+        assert_eq!(cache.get("unknown").await, None);
+    }
+
+    #[tokio::test]
+    async fn positive_insert_overrides_stale_negative_entry() {
+        let cache = LookupCache::new(&test_config());
+
+        cache.insert("key".to_string(), None).await;
+        assert_eq!(cache.get("key").await, Some(None));
+
+        cache.insert("key".to_string(), Some("alice".to_string())).await;
+        assert_eq!(cache.get("key").await, Some(Some("alice".to_string())));
+    }
+
+    #[tokio::test]
+    async fn negative_insert_overrides_stale_positive_entry() {
+        let cache = LookupCache::new(&test_config());
+
+        cache.insert("key".to_string(), Some("alice".to_string())).await;
+        assert_eq!(cache.get("key").await, Some(Some("alice".to_string())));
+
+        cache.insert("key".to_string(), None).await;
+        assert_eq!(
+            cache.get("key").await,
+            Some(None),
+            "a fresh negative result must not be shadowed by a stale positive entry"
+        );
+    }
+
+    #[tokio::test]
+    async fn invalidate_clears_both_caches() {
+        let cache = LookupCache::new(&test_config());
+
+        cache.insert("key".to_string(), Some("alice".to_string())).await;
+        cache.invalidate("key").await;
+
+        assert_eq!(cache.get("key").await, None);
+    }
+}
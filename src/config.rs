@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use starknet::core::types::FieldElement;
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ContractConfig {
+    pub starknetid: FieldElement,
+    pub argent_multicall: FieldElement,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct VariablesConfig {
+    pub discord_api_url: String,
+    pub discord_token: String,
+    pub github_api_url: String,
+    pub twitter_user_lookup_url: String,
+    pub twitter_consumer_key: String,
+    pub twitter_consumer_secret: String,
+    pub twitter_access_token: String,
+    pub twitter_access_token_secret: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct EvmRecordVerifier {
+    pub field: String,
+    /// Name of a `HandlerDecl` registered in `Config::handlers`, resolved at
+    /// runtime through the `HandlerRegistry` instead of a closed enum.
+    pub handler: String,
+    pub verifier_contracts: Vec<FieldElement>,
+}
+
+/// Declares a `RecordHandler` implementation by name so it can be referenced
+/// from an `EvmRecordVerifier::handler` without recompiling the binary for
+/// every new off-chain resolver. `Static`/`Discord`/`Github`/`Twitter` are
+/// built-in implementations; `Rest` lets an operator wire up a brand-new
+/// social platform purely through config.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(tag = "kind")]
+pub enum HandlerDecl {
+    Static,
+    Discord,
+    Github,
+    Twitter,
+    Rest {
+        /// URL template with an `{id}` placeholder, e.g. `https://example.com/users/{id}`.
+        url_template: String,
+        /// RFC 6901 JSON pointer locating the resolved name in the response body.
+        json_pointer: String,
+    },
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct CacheConfig {
+    /// TTL in seconds for a resolved (positive) lookup.
+    pub positive_ttl_seconds: u64,
+    /// TTL in seconds for a negative (not-found) lookup. Kept shorter than
+    /// `positive_ttl_seconds` so a record that just got set doesn't stay hidden.
+    pub negative_ttl_seconds: u64,
+    /// Max number of entries held per cache before older entries are evicted.
+    pub max_capacity: u64,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        CacheConfig {
+            positive_ttl_seconds: 300,
+            negative_ttl_seconds: 30,
+            max_capacity: 10_000,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct HttpConfig {
+    pub connect_timeout_seconds: u64,
+    pub request_timeout_seconds: u64,
+    /// Blocks DNS resolution to private/loopback/link-local/unique-local
+    /// ranges before any outbound verifier request. Only disable this for
+    /// local development against a verifier API running on localhost.
+    #[serde(default = "default_ssrf_guard")]
+    pub ssrf_guard: bool,
+}
+
+fn default_ssrf_guard() -> bool {
+    true
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        HttpConfig {
+            connect_timeout_seconds: 5,
+            request_timeout_seconds: 10,
+            ssrf_guard: true,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Config {
+    pub contracts: ContractConfig,
+    pub variables: VariablesConfig,
+    pub evm_verifiers: Vec<EvmRecordVerifier>,
+    /// Handler declarations keyed by the name an `EvmRecordVerifier.handler`
+    /// refers to. Built via `HandlerRegistry::build`.
+    pub handlers: HashMap<String, HandlerDecl>,
+    #[serde(default)]
+    pub cache: CacheConfig,
+    #[serde(default)]
+    pub http: HttpConfig,
+}
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+use serde::Deserialize;
+use starknet::core::types::FieldElement;
+
+use crate::config::{Config, HandlerDecl};
+
+use super::twitter_oauth;
+
+#[derive(Deserialize, Debug)]
+struct GithubUser {
+    login: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct DiscordUser {
+    username: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct TwitterUser {
+    screen_name: String,
+}
+
+/// Resolves an on-chain social id (e.g. a Discord/GitHub user id) to a
+/// human-readable name. Implementations are looked up by name through the
+/// `HandlerRegistry`, so adding a new off-chain resolver no longer requires
+/// editing a closed enum — just register it, either as a built-in or as a
+/// `RestHandler` declared entirely in config.
+#[async_trait]
+pub trait RecordHandler: Send + Sync {
+    async fn resolve(&self, config: &Config, client: &Client, id: FieldElement) -> Result<String>;
+}
+
+struct StaticHandler;
+
+#[async_trait]
+impl RecordHandler for StaticHandler {
+    async fn resolve(&self, _config: &Config, _client: &Client, id: FieldElement) -> Result<String> {
+        Ok(FieldElement::to_string(&id))
+    }
+}
+
+struct DiscordHandler;
+
+#[async_trait]
+impl RecordHandler for DiscordHandler {
+    async fn resolve(&self, config: &Config, client: &Client, id: FieldElement) -> Result<String> {
+        let social_id = FieldElement::to_string(&id);
+        let url = format!("{}/users/{}", config.variables.discord_api_url, social_id);
+        let resp = client
+            .get(&url)
+            .header("Content-Type", "application/json")
+            .header(
+                "Authorization",
+                format!("Bot {}", config.variables.discord_token),
+            )
+            .send()
+            .await?
+            .json::<DiscordUser>()
+            .await
+            .context("Failed to parse JSON response from Discord API")?;
+
+        Ok(resp.username)
+    }
+}
+
+struct GithubHandler;
+
+#[async_trait]
+impl RecordHandler for GithubHandler {
+    async fn resolve(&self, config: &Config, client: &Client, id: FieldElement) -> Result<String> {
+        let social_id = FieldElement::to_string(&id);
+        let url = format!("{}/user/{}", config.variables.github_api_url, social_id);
+        let response = client
+            .get(&url)
+            .send()
+            .await
+            .context("Failed to send request to GitHub")?;
+
+        if response.status() != StatusCode::OK {
+            anyhow::bail!("GitHub API returned non-OK status: {}", response.status());
+        }
+
+        let text = response
+            .text()
+            .await
+            .context("Failed to read response text")?;
+        let user: GithubUser =
+            serde_json::from_str(&text).context("Failed to deserialize GitHub response")?;
+
+        Ok(user.login)
+    }
+}
+
+struct TwitterHandler;
+
+#[async_trait]
+impl RecordHandler for TwitterHandler {
+    async fn resolve(&self, config: &Config, client: &Client, id: FieldElement) -> Result<String> {
+        let social_id = FieldElement::to_string(&id);
+        let url = config.variables.twitter_user_lookup_url.clone();
+        let query_params = [("user_id", social_id.as_str())];
+        let authorization = twitter_oauth::build_authorization_header(
+            &config.variables,
+            "GET",
+            &url,
+            &query_params,
+        );
+
+        let response = client
+            .get(&url)
+            .header("Authorization", authorization)
+            .query(&query_params)
+            .send()
+            .await
+            .context("Failed to send request to Twitter")?;
+
+        if response.status() != StatusCode::OK {
+            anyhow::bail!("Twitter API returned non-OK status: {}", response.status());
+        }
+
+        let user: TwitterUser = response
+            .json()
+            .await
+            .context("Failed to deserialize Twitter response")?;
+
+        Ok(user.screen_name)
+    }
+}
+
+/// A generic REST resolver declared entirely from config: substitutes `{id}`
+/// into `url_template`, then extracts the value at `json_pointer` (RFC 6901,
+/// e.g. `/data/user/screen_name`) from the JSON response body.
+struct RestHandler {
+    url_template: String,
+    json_pointer: String,
+}
+
+#[async_trait]
+impl RecordHandler for RestHandler {
+    async fn resolve(&self, _config: &Config, client: &Client, id: FieldElement) -> Result<String> {
+        let url = self
+            .url_template
+            .replace("{id}", &FieldElement::to_string(&id));
+        let response = client
+            .get(&url)
+            .send()
+            .await
+            .context("Failed to send request to configured REST resolver")?;
+
+        if response.status() != StatusCode::OK {
+            anyhow::bail!("REST resolver returned non-OK status: {}", response.status());
+        }
+
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .context("Failed to parse REST resolver response as JSON")?;
+        body.pointer(&self.json_pointer)
+            .and_then(|value| value.as_str())
+            .map(str::to_owned)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "REST resolver response had nothing at JSON pointer `{}`",
+                    self.json_pointer
+                )
+            })
+    }
+}
+
+/// Looks up a `RecordHandler` by the name declared on an `EvmRecordVerifier`.
+pub struct HandlerRegistry {
+    handlers: HashMap<String, Box<dyn RecordHandler>>,
+}
+
+impl HandlerRegistry {
+    pub fn build(declarations: &HashMap<String, HandlerDecl>) -> Self {
+        let mut handlers: HashMap<String, Box<dyn RecordHandler>> = HashMap::new();
+        for (name, decl) in declarations {
+            let handler: Box<dyn RecordHandler> = match decl {
+                HandlerDecl::Static => Box::new(StaticHandler),
+                HandlerDecl::Discord => Box::new(DiscordHandler),
+                HandlerDecl::Github => Box::new(GithubHandler),
+                HandlerDecl::Twitter => Box::new(TwitterHandler),
+                HandlerDecl::Rest {
+                    url_template,
+                    json_pointer,
+                } => Box::new(RestHandler {
+                    url_template: url_template.clone(),
+                    json_pointer: json_pointer.clone(),
+                }),
+            };
+            handlers.insert(name.clone(), handler);
+        }
+        HandlerRegistry { handlers }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn RecordHandler> {
+        self.handlers.get(name).map(AsRef::as_ref)
+    }
+}
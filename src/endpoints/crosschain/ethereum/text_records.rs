@@ -1,7 +1,5 @@
-use anyhow::{anyhow, Context, Result};
-use reqwest::{Client, StatusCode};
-use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use anyhow::Result;
+use reqwest::Client;
 use starknet::{
     core::{
         types::{BlockId, BlockTag, FieldElement, FunctionCall},
@@ -11,120 +9,53 @@ use starknet::{
     providers::{jsonrpc::HttpTransport, JsonRpcClient, Provider},
 };
 
+use crate::cache::LookupCache;
 use crate::config::{Config, EvmRecordVerifier};
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub enum HandlerType {
-    Static,
-    GetDiscordName,
-    GetGithubName,
-    GetTwitterName,
-}
-
-#[derive(Deserialize, Debug)]
-struct GithubUser {
-    login: String,
-}
-
-#[derive(Deserialize, Debug)]
-struct DiscordUser {
-    username: String,
-}
+use super::record_handlers::HandlerRegistry;
 
 impl EvmRecordVerifier {
-    pub async fn execute_handler(&self, config: &Config, id: FieldElement) -> Result<String> {
-        match self.handler {
-            HandlerType::Static => Ok(FieldElement::to_string(&id)),
-            HandlerType::GetDiscordName => self.get_discord_name(config, id).await,
-            HandlerType::GetGithubName => self.get_github_name(config, id).await,
-            HandlerType::GetTwitterName => self.get_twitter_name(config, id).await,
-        }
-    }
-
-    async fn get_discord_name(&self, config: &Config, id: FieldElement) -> Result<String> {
-        let social_id = FieldElement::to_string(&id);
-        let url = format!("{}/users/{}", config.variables.discord_api_url, social_id);
-        let client = Client::new();
-        let resp = client
-            .get(&url)
-            .header("Content-Type", "application/json")
-            .header(
-                "Authorization",
-                format!("Bot {}", config.variables.discord_token),
-            )
-            .send()
-            .await?
-            .json::<DiscordUser>()
-            .await
-            .context("Failed to parse JSON response from Discord API")?;
-
-        Ok(resp.username)
-    }
-    async fn get_github_name(&self, config: &Config, id: FieldElement) -> Result<String> {
-        let social_id = FieldElement::to_string(&id);
-        let url = format!("{}/user/{}", config.variables.github_api_url, social_id);
-        let client = Client::builder()
-            .user_agent("request")
-            .build()
-            .context("Failed to build HTTP client")?;
-        let response = client
-            .get(&url)
-            .send()
-            .await
-            .context("Failed to send request to GitHub")?;
-
-        if response.status() != StatusCode::OK {
-            anyhow::bail!("GitHub API returned non-OK status: {}", response.status());
-        }
-
-        let text = response
-            .text()
-            .await
-            .context("Failed to read response text")?;
-        let user: GithubUser =
-            serde_json::from_str(&text).context("Failed to deserialize GitHub response")?;
-
-        Ok(user.login)
-    }
-
-    async fn get_twitter_name(&self, config: &Config, id: FieldElement) -> Result<String> {
-        let social_id = FieldElement::to_string(&id);
-        let client = Client::new();
-        let response = client
-            .get(format!(
-                "{}/get-user-by-id",
-                config.variables.twitter_api_url
-            ))
-            .header("X-RapidAPI-Key", config.variables.twitter_api_key.clone())
-            .header("X-RapidAPI-Host", "twttrapi.p.rapidapi.com")
-            .query(&[("user_id", &social_id)])
-            .send()
-            .await?;
-
-        if response.status() != StatusCode::OK {
-            anyhow::bail!("Twitter API returned non-OK status: {}", response.status());
-        }
-        let response_body = response.text().await?;
-        let json: Value = serde_json::from_str(&response_body)?;
-        let screen_name = json
-            .get("data")
-            .and_then(|data| data.get("user_result"))
-            .and_then(|user_result| user_result.get("result"))
-            .and_then(|result| result.get("legacy"))
-            .and_then(|legacy| legacy.get("screen_name"))
-            .and_then(|screen_name| screen_name.as_str())
-            .ok_or_else(|| anyhow!("Failed to extract screen name"));
-
-        Ok(screen_name.map(|name| name.to_string()).unwrap())
+    pub async fn execute_handler(
+        &self,
+        config: &Config,
+        client: &Client,
+        registry: &HandlerRegistry,
+        id: FieldElement,
+    ) -> Result<String> {
+        let handler = registry
+            .get(&self.handler)
+            .ok_or_else(|| anyhow::anyhow!("No handler registered under `{}`", self.handler))?;
+
+        let result = handler.resolve(config, client, id).await;
+        crate::metrics::record_verifier_call(&self.handler, result.is_ok());
+        result
     }
 }
 
 pub async fn get_verifier_data(
     config: &Config,
     provider: &JsonRpcClient<HttpTransport>,
+    cache: &LookupCache,
+    client: &Client,
+    registry: &HandlerRegistry,
     id: FieldElement,
     record_config: &EvmRecordVerifier,
 ) -> Option<String> {
+    let cache_key = format!(
+        "verifier:{}:{}:{}",
+        record_config.field,
+        record_config
+            .verifier_contracts
+            .iter()
+            .map(FieldElement::to_string)
+            .collect::<Vec<_>>()
+            .join(","),
+        id
+    );
+    if let Some(cached) = cache.get(&cache_key).await {
+        return cached;
+    }
+
     let mut calls: Vec<FieldElement> = vec![FieldElement::from(record_config.verifier_contracts.len())];
     for verifier in &record_config.verifier_contracts {
         calls.push(config.contracts.starknetid);
@@ -147,25 +78,43 @@ pub async fn get_verifier_data(
         )
         .await;
 
-    match call_result {
+    let result = match call_result {
         Ok(result) => {
             let social_id = find_social_id(&result);
             if social_id == FieldElement::ZERO {
-                return None;
-            }
-            match record_config.execute_handler(config, social_id).await {
-                Ok(name) => Some(name),
-                Err(e) => {
-                    println!("Error while executing handler: {:?}", e);
-                    None
+                // Genuine on-chain result: no verifier data set for this id.
+                // Safe to cache as a negative hit.
+                Ok(None)
+            } else {
+                match record_config
+                    .execute_handler(config, client, registry, social_id)
+                    .await
+                {
+                    Ok(name) => Ok(Some(name)),
+                    // A handler failure (rate limit, timeout, upstream 5xx) is
+                    // not the same thing as "this record doesn't exist" -
+                    // don't cache it, so the next request retries instead of
+                    // being stuck behind `negative_ttl_seconds`.
+                    Err(e) => {
+                        println!("Error while executing handler: {:?}", e);
+                        Err(())
+                    }
                 }
             }
-
         }
         Err(err) => {
             println!("Error while fetching balances: {:?}", err);
-            None
+            crate::metrics::record_rpc_aggregate_error();
+            Err(())
+        }
+    };
+
+    match result {
+        Ok(resolved) => {
+            cache.insert(cache_key, resolved.clone()).await;
+            resolved
         }
+        Err(()) => None,
     }
 }
 
@@ -187,9 +136,15 @@ fn find_social_id(result: &[FieldElement]) -> FieldElement {
 pub async fn get_unbounded_user_data(
     config: &Config,
     provider: &JsonRpcClient<HttpTransport>,
+    cache: &LookupCache,
     id: FieldElement,
     field: &str,
 ) -> Option<String> {
+    let cache_key = format!("unbounded:{}:{}", field, id);
+    if let Some(cached) = cache.get(&cache_key).await {
+        return cached;
+    }
+
     let call_result = provider
         .call(
             FunctionCall {
@@ -204,22 +159,26 @@ pub async fn get_unbounded_user_data(
             BlockId::Tag(BlockTag::Latest),
         )
         .await;
-    match call_result {
+    let resolved = match call_result {
         Ok(result) => {
             if result[0] == FieldElement::ZERO {
-                return None;
+                None
+            } else {
+                let res = result
+                    .iter()
+                    .skip(1)
+                    .filter_map(|val| parse_cairo_short_string(val).ok())
+                    .collect::<Vec<String>>() // Collect into a vector of strings
+                    .join("");
+                Some(res)
             }
-            let res = result
-                .iter()
-                .skip(1)
-                .filter_map(|val| parse_cairo_short_string(val).ok())
-                .collect::<Vec<String>>() // Collect into a vector of strings
-                .join("");
-            Some(res)
         }
         Err(e) => {
             println!("Error while fetchingverifier data: {:?}", e);
             None
         }
-    }
+    };
+
+    cache.insert(cache_key, resolved.clone()).await;
+    resolved
 }
@@ -0,0 +1,181 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use hmac::{Hmac, Mac};
+use rand::Rng;
+use sha1::Sha1;
+
+use crate::config::VariablesConfig;
+
+type HmacSha1 = Hmac<Sha1>;
+
+/// Characters reqwest/percent-encoding's `NON_ALPHANUMERIC` set leaves
+/// untouched per RFC 3986 (and the OAuth 1.0a spec requires we don't escape).
+const RFC3986_UNRESERVED: &percent_encoding::AsciiSet = &percent_encoding::NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+fn percent_encode(value: &str) -> String {
+    percent_encoding::utf8_percent_encode(value, RFC3986_UNRESERVED).to_string()
+}
+
+/// Builds the `Authorization: OAuth ...` header for a signed GET request,
+/// per the OAuth 1.0a signing spec used by Twitter/X's API.
+pub fn build_authorization_header(
+    vars: &VariablesConfig,
+    method: &str,
+    url: &str,
+    query_params: &[(&str, &str)],
+) -> String {
+    let nonce: String = rand::thread_rng()
+        .sample_iter(&rand::distributions::Alphanumeric)
+        .take(32)
+        .map(char::from)
+        .collect();
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock is before the Unix epoch")
+        .as_secs()
+        .to_string();
+
+    build_authorization_header_with(vars, method, url, query_params, &nonce, &timestamp)
+}
+
+/// Same as `build_authorization_header` but with `oauth_nonce`/`oauth_timestamp`
+/// supplied by the caller instead of generated, so the signing math can be
+/// tested deterministically.
+fn build_authorization_header_with(
+    vars: &VariablesConfig,
+    method: &str,
+    url: &str,
+    query_params: &[(&str, &str)],
+    nonce: &str,
+    timestamp: &str,
+) -> String {
+    let mut oauth_params = vec![
+        ("oauth_consumer_key", vars.twitter_consumer_key.clone()),
+        ("oauth_nonce", nonce.to_string()),
+        ("oauth_signature_method", "HMAC-SHA1".to_string()),
+        ("oauth_timestamp", timestamp.to_string()),
+        ("oauth_token", vars.twitter_access_token.clone()),
+        ("oauth_version", "1.0".to_string()),
+    ];
+
+    let mut all_params: Vec<(String, String)> = oauth_params
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.clone()))
+        .chain(
+            query_params
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string())),
+        )
+        .collect();
+    all_params.sort_by(|a, b| percent_encode(&a.0).cmp(&percent_encode(&b.0)));
+
+    let param_string = all_params
+        .iter()
+        .map(|(k, v)| format!("{}={}", percent_encode(k), percent_encode(v)))
+        .collect::<Vec<_>>()
+        .join("&");
+
+    let base_string = format!(
+        "{}&{}&{}",
+        method.to_uppercase(),
+        percent_encode(url),
+        percent_encode(&param_string)
+    );
+
+    let signing_key = format!(
+        "{}&{}",
+        percent_encode(&vars.twitter_consumer_secret),
+        percent_encode(&vars.twitter_access_token_secret)
+    );
+
+    let mut mac =
+        HmacSha1::new_from_slice(signing_key.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(base_string.as_bytes());
+    let signature = base64::encode(mac.finalize().into_bytes());
+
+    oauth_params.push(("oauth_signature", signature));
+    oauth_params.sort_by(|a, b| a.0.cmp(b.0));
+
+    let header_params = oauth_params
+        .into_iter()
+        .map(|(k, v)| format!("{}=\"{}\"", k, percent_encode(&v)))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!("OAuth {}", header_params)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_vars() -> VariablesConfig {
+        // Fixture values straight from Twitter/X's own OAuth 1.0a signing
+        // walkthrough: https://developer.twitter.com/en/docs/authentication/oauth-1-0a/creating-a-signature
+        VariablesConfig {
+            discord_api_url: String::new(),
+            discord_token: String::new(),
+            github_api_url: String::new(),
+            twitter_user_lookup_url: String::new(),
+            twitter_consumer_key: "xvz1evFS4wEEPTGEFPHBog".to_string(),
+            twitter_consumer_secret: "kAcSOqF21Fu85e7zjz7ZN2U4ZRhfV3WpwPAoE3Z7kBw".to_string(),
+            twitter_access_token: "370773112-GmHxMAgYyLbNEtIKZeRNFsMKPR9EyMZeS9weJAEb"
+                .to_string(),
+            twitter_access_token_secret: "LswwdoUaIvS8ltyTt5jkRh4J50vUPVVHtR2YPi5kE".to_string(),
+        }
+    }
+
+    #[test]
+    fn matches_twitter_documented_signature_example() {
+        let vars = test_vars();
+        let header = build_authorization_header_with(
+            &vars,
+            "POST",
+            "https://api.twitter.com/1.1/statuses/update.json",
+            &[
+                ("status", "Hello Ladies + Gentlemen, a signed OAuth request!"),
+                ("include_entities", "true"),
+            ],
+            "kYjzVBB8Y0ZFabxSWbWovY3uYSQ2pTgmZeNu2VS4cg",
+            "1318622958",
+        );
+
+        // Expected signature from the walkthrough: hCtSmYh+iHYCEqBWrE7C7hYmtUk=
+        assert!(
+            header.contains("oauth_signature=\"hCtSmYh%2BiHYCEqBWrE7C7hYmtUk%3D\""),
+            "header did not contain the expected signature: {header}"
+        );
+    }
+
+    #[test]
+    fn percent_encode_leaves_unreserved_characters_untouched() {
+        assert_eq!(percent_encode("Ladies + Gentlemen"), "Ladies%20%2B%20Gentlemen");
+        assert_eq!(percent_encode("abc-._~123"), "abc-._~123");
+    }
+
+    #[test]
+    fn same_inputs_produce_a_stable_signature() {
+        let vars = test_vars();
+        let first = build_authorization_header_with(
+            &vars,
+            "GET",
+            "https://api.twitter.com/1.1/users/show.json",
+            &[("user_id", "12345")],
+            "fixed-nonce",
+            "1700000000",
+        );
+        let second = build_authorization_header_with(
+            &vars,
+            "GET",
+            "https://api.twitter.com/1.1/users/show.json",
+            &[("user_id", "12345")],
+            "fixed-nonce",
+            "1700000000",
+        );
+        assert_eq!(first, second);
+    }
+}
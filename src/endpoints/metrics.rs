@@ -0,0 +1,9 @@
+use crate::models::AppState;
+use axum::{extract::State, response::IntoResponse};
+use axum_auto_routes::route;
+use std::sync::Arc;
+
+#[route(get, "/metrics", crate::endpoints::metrics)]
+pub async fn handler(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    state.metrics_handle.render()
+}
@@ -161,9 +161,14 @@ pub async fn handler(
         },
     ];
 
+    let aggregation_start = std::time::Instant::now();
     let cursor = id_owners
         .aggregate(pipeline, AggregateOptions::default())
         .await;
+    crate::metrics::record_mongo_aggregation_duration(
+        "get_non_subscribed_domains",
+        aggregation_start.elapsed().as_secs_f64(),
+    );
     match cursor {
         Ok(mut cursor) => {
             let mut domains_set: HashSet<String> = HashSet::new();
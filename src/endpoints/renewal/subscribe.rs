@@ -0,0 +1,220 @@
+use std::{collections::HashMap, convert::Infallible, sync::Arc, time::Duration};
+
+use axum::{
+    extract::{Query, State},
+    response::sse::{Event, KeepAlive, Sse},
+};
+use axum_auto_routes::route;
+use futures::{stream::Stream, StreamExt};
+use mongodb::{bson::doc, options::AggregateOptions};
+use serde::{Deserialize, Serialize};
+use starknet::core::types::FieldElement;
+use tokio_stream::wrappers::{errors::BroadcastStreamRecvError, BroadcastStream};
+
+use crate::{models::AppState, utils::to_hex};
+
+/// Threshold, in seconds, under which a domain is considered "expiring soon".
+const EXPIRY_WARNING_WINDOW_SECONDS: i64 = 30 * 24 * 60 * 60;
+/// How often the background watcher re-checks domain/auto-renew state.
+const WATCH_INTERVAL_SECONDS: u64 = 60;
+/// Capacity of the broadcast channel backing `/renewal/subscribe`.
+pub const RENEWAL_EVENTS_CAPACITY: usize = 1024;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum RenewalEvent {
+    ExpiringSoon { domain: String, expiry: i64 },
+    AutoRenewEnabled { domain: String },
+    AutoRenewDisabled { domain: String },
+}
+
+impl RenewalEvent {
+    fn event_name(&self) -> &'static str {
+        match self {
+            RenewalEvent::ExpiringSoon { .. } => "expiring_soon",
+            RenewalEvent::AutoRenewEnabled { .. } => "auto_renew_enabled",
+            RenewalEvent::AutoRenewDisabled { .. } => "auto_renew_disabled",
+        }
+    }
+}
+
+/// One broadcast message: the owner address the event is about, and the
+/// event itself. Every subscriber receives every message and filters down
+/// to the address it cares about.
+#[derive(Debug, Clone)]
+pub struct RenewalNotification {
+    pub addr: String,
+    pub event: RenewalEvent,
+}
+
+#[derive(Deserialize)]
+pub struct SubscribeQuery {
+    addr: FieldElement,
+}
+
+#[route(get, "/renewal/subscribe", crate::endpoints::renewal::subscribe)]
+pub async fn handler(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<SubscribeQuery>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let addr = to_hex(&query.addr);
+    let receiver = state.renewal_events.subscribe();
+
+    let stream = BroadcastStream::new(receiver).filter_map(
+        move |notification: Result<RenewalNotification, BroadcastStreamRecvError>| {
+            let notification = notification.ok();
+            let event = notification.and_then(|n| {
+                if n.addr != addr {
+                    return None;
+                }
+                Event::default().event(n.event.event_name()).json_data(&n.event).ok()
+            });
+            futures::future::ready(event.map(Ok))
+        },
+    );
+
+    Sse::new(stream).keep_alive(
+        KeepAlive::new()
+            .interval(Duration::from_secs(15))
+            .text("heartbeat"),
+    )
+}
+
+/// Observed state for one (owner, domain) pair: whether auto-renew is
+/// enabled (via either the native or altcoin flow) and the domain's real
+/// on-chain expiry timestamp, in seconds.
+#[derive(Debug, Clone, Copy)]
+struct DomainState {
+    enabled: bool,
+    expiry: i64,
+}
+
+/// Periodically re-runs the expiry/auto-renew aggregation and diffs it
+/// against the previous run, publishing a `RenewalEvent` for every address
+/// whose observable state changed. Meant to be spawned once at startup.
+pub async fn watch_renewals(state: Arc<AppState>) {
+    let mut previous: HashMap<(String, String), DomainState> = HashMap::new();
+    let mut interval = tokio::time::interval(Duration::from_secs(WATCH_INTERVAL_SECONDS));
+
+    loop {
+        interval.tick().await;
+        let current = match collect_renewal_state(&state).await {
+            Ok(state) => state,
+            Err(e) => {
+                println!("Error while collecting renewal state: {:?}", e);
+                continue;
+            }
+        };
+
+        for ((addr, domain), current_state) in &current {
+            let prev = previous.get(&(addr.clone(), domain.clone())).copied();
+            let was_enabled = prev.map(|p| p.enabled).unwrap_or(current_state.enabled);
+            if current_state.enabled && !was_enabled {
+                let _ = state.renewal_events.send(RenewalNotification {
+                    addr: addr.clone(),
+                    event: RenewalEvent::AutoRenewEnabled {
+                        domain: domain.clone(),
+                    },
+                });
+            } else if !current_state.enabled && was_enabled {
+                let _ = state.renewal_events.send(RenewalNotification {
+                    addr: addr.clone(),
+                    event: RenewalEvent::AutoRenewDisabled {
+                        domain: domain.clone(),
+                    },
+                });
+            }
+
+            if prev.is_none() {
+                let _ = state.renewal_events.send(RenewalNotification {
+                    addr: addr.clone(),
+                    event: RenewalEvent::ExpiringSoon {
+                        domain: domain.clone(),
+                        expiry: current_state.expiry,
+                    },
+                });
+            }
+        }
+
+        previous = current;
+    }
+}
+
+type RenewalState = HashMap<(String, String), DomainState>;
+
+async fn collect_renewal_state(state: &Arc<AppState>) -> anyhow::Result<RenewalState> {
+    let domains = state
+        .db
+        .collection::<mongodb::bson::Document>("domains");
+    let now = mongodb::bson::DateTime::now().timestamp_millis() / 1000;
+
+    let pipeline = vec![
+        doc! {
+            "$match": {
+                "_cursor.to": null,
+                "root": true,
+                "expiry": { "$lte": now + EXPIRY_WARNING_WINDOW_SECONDS }
+            }
+        },
+        doc! {
+            "$lookup": {
+                "from": "auto_renew_flows",
+                "let": { "domain_name": "$domain" },
+                "pipeline": [
+                    doc! {
+                        "$match": {
+                            "$expr": { "$eq": ["$domain", "$$domain_name"] },
+                            "_cursor.to": null
+                        }
+                    }
+                ],
+                "as": "renew_flows"
+            }
+        },
+        doc! { "$unwind": { "path": "$renew_flows", "preserveNullAndEmptyArrays": true } },
+        doc! {
+            "$lookup": {
+                "from": "auto_renew_flows_altcoins",
+                "let": { "domain_name": "$domain" },
+                "pipeline": [
+                    doc! {
+                        "$match": {
+                            "$expr": { "$eq": ["$domain", "$$domain_name"] },
+                            "_cursor.to": null
+                        }
+                    }
+                ],
+                "as": "renew_flows_altcoins"
+            }
+        },
+        doc! { "$unwind": { "path": "$renew_flows_altcoins", "preserveNullAndEmptyArrays": true } },
+        doc! {
+            "$project": {
+                "_id": 0,
+                "owner": 1,
+                "domain": 1,
+                "expiry": 1,
+                "enabled": {
+                    "$or": [
+                        { "$ifNull": ["$renew_flows.enabled", false] },
+                        { "$ifNull": ["$renew_flows_altcoins.enabled", false] }
+                    ]
+                }
+            }
+        },
+    ];
+
+    let mut cursor = domains
+        .aggregate(pipeline, AggregateOptions::default())
+        .await?;
+
+    let mut result = RenewalState::new();
+    while let Some(doc) = cursor.next().await.transpose()? {
+        let domain = doc.get_str("domain")?.to_string();
+        let owner = doc.get_str("owner").unwrap_or_default().to_string();
+        let enabled = doc.get_bool("enabled").unwrap_or(false);
+        let expiry = doc.get_i64("expiry").unwrap_or(0);
+        result.insert((owner, domain), DomainState { enabled, expiry });
+    }
+    Ok(result)
+}
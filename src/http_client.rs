@@ -0,0 +1,133 @@
+use std::future::Future;
+use std::net::{IpAddr, SocketAddr};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use hyper::client::connect::dns::{GaiResolver, Name};
+use reqwest::dns::{Addrs, Resolve, Resolving};
+use tower::Service;
+
+use crate::config::Config;
+
+/// Rejects resolved addresses that point at private, loopback, link-local or
+/// unique-local ranges, so a misconfigured (or attacker-controlled) verifier
+/// API base URL can't be used to reach internal services.
+#[derive(Clone)]
+struct SsrfGuardedResolver {
+    inner: GaiResolver,
+}
+
+impl SsrfGuardedResolver {
+    fn new() -> Self {
+        SsrfGuardedResolver {
+            inner: GaiResolver::new(),
+        }
+    }
+}
+
+fn is_blocked_v4(v4: &std::net::Ipv4Addr) -> bool {
+    v4.is_private() || v4.is_loopback() || v4.is_link_local() || v4.is_unspecified()
+}
+
+fn is_blocked(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => is_blocked_v4(v4),
+        IpAddr::V6(v6) => {
+            // An IPv4-mapped address (`::ffff:a.b.c.d`) must be judged by the
+            // v4 rules, otherwise e.g. `::ffff:127.0.0.1` sails straight past
+            // every v6 check below.
+            if let Some(mapped) = v6.to_ipv4_mapped() {
+                return is_blocked_v4(&mapped);
+            }
+            v6.is_loopback()
+                || v6.is_unspecified()
+                || v6.is_unicast_link_local()
+                || v6.is_unique_local()
+        }
+    }
+}
+
+impl Resolve for SsrfGuardedResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let mut inner = self.inner.clone();
+        Box::pin(async move {
+            let addrs = inner
+                .call(name)
+                .await
+                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> { Box::new(e) })?;
+            let allowed: Vec<SocketAddr> = addrs.filter(|addr| !is_blocked(&addr.ip())).collect();
+            Ok(Box::new(allowed.into_iter()) as Addrs)
+        }) as Pin<Box<dyn Future<Output = Result<Addrs, Box<dyn std::error::Error + Send + Sync>>> + Send>>
+    }
+}
+
+/// Builds the single `reqwest::Client` shared by every verifier handler.
+///
+/// Reusing one client lets handlers reuse connection pools instead of
+/// paying a fresh TLS/TCP handshake per request, and the custom resolver
+/// blocks SSRF attempts against private/loopback/link-local/unique-local
+/// ranges when `ssrf_guard` is enabled in `Config`.
+pub fn build_http_client(config: &Config) -> Result<reqwest::Client> {
+    let mut builder = reqwest::Client::builder()
+        .user_agent("starknetid-api")
+        .connect_timeout(Duration::from_secs(config.http.connect_timeout_seconds))
+        .timeout(Duration::from_secs(config.http.request_timeout_seconds));
+
+    if config.http.ssrf_guard {
+        builder = builder.dns_resolver(Arc::new(SsrfGuardedResolver::new()));
+    }
+
+    builder.build().context("Failed to build shared HTTP client")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    #[test]
+    fn blocks_ipv4_private_loopback_link_local_and_unspecified() {
+        assert!(is_blocked(&IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1))));
+        assert!(is_blocked(&IpAddr::V4(Ipv4Addr::new(172, 16, 0, 1))));
+        assert!(is_blocked(&IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1))));
+        assert!(is_blocked(&IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1))));
+        assert!(is_blocked(&IpAddr::V4(Ipv4Addr::new(169, 254, 1, 1))));
+        assert!(is_blocked(&IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0))));
+    }
+
+    #[test]
+    fn allows_public_ipv4() {
+        assert!(!is_blocked(&IpAddr::V4(Ipv4Addr::new(8, 8, 8, 8))));
+    }
+
+    #[test]
+    fn blocks_ipv6_loopback_unspecified_link_local_and_unique_local() {
+        assert!(is_blocked(&IpAddr::V6(Ipv6Addr::LOCALHOST)));
+        assert!(is_blocked(&IpAddr::V6(Ipv6Addr::UNSPECIFIED)));
+        assert!(is_blocked(&IpAddr::V6(Ipv6Addr::new(
+            0xfe80, 0, 0, 0, 0, 0, 0, 1
+        ))));
+        assert!(is_blocked(&IpAddr::V6(Ipv6Addr::new(
+            0xfc00, 0, 0, 0, 0, 0, 0, 1
+        ))));
+    }
+
+    #[test]
+    fn allows_public_ipv6() {
+        assert!(!is_blocked(&IpAddr::V6(Ipv6Addr::new(
+            0x2606, 0x4700, 0, 0, 0, 0, 0, 1
+        ))));
+    }
+
+    #[test]
+    fn blocks_ipv4_mapped_ipv6_private_and_loopback_addresses() {
+        assert!(is_blocked(&IpAddr::V6(
+            Ipv4Addr::new(127, 0, 0, 1).to_ipv6_mapped()
+        )));
+        assert!(is_blocked(&IpAddr::V6(
+            Ipv4Addr::new(10, 0, 0, 1).to_ipv6_mapped()
+        )));
+    }
+}
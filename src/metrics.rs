@@ -0,0 +1,65 @@
+use std::time::Instant;
+
+use axum::{extract::MatchedPath, http::Request, middleware::Next, response::IntoResponse};
+use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
+
+/// Installs the global Prometheus recorder and returns a handle that can
+/// render the current snapshot for the `/metrics` endpoint.
+pub fn install_recorder() -> PrometheusHandle {
+    PrometheusBuilder::new()
+        .install_recorder()
+        .expect("Failed to install Prometheus recorder")
+}
+
+/// Axum middleware that records request count, latency and status-code
+/// breakdown for every route, labelled by the route's matched path.
+pub async fn track_http_metrics<B>(req: Request<B>, next: Next<B>) -> impl IntoResponse {
+    let path = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|p| p.as_str().to_owned())
+        .unwrap_or_else(|| req.uri().path().to_owned());
+    let method = req.method().clone();
+
+    let start = Instant::now();
+    let response = next.run(req).await;
+    let latency = start.elapsed().as_secs_f64();
+    let status = response.status().as_u16().to_string();
+
+    let labels = [
+        ("method", method.to_string()),
+        ("path", path),
+        ("status", status),
+    ];
+    metrics::counter!("http_requests_total", 1, &labels);
+    metrics::histogram!("http_request_duration_seconds", latency, &labels);
+
+    response
+}
+
+/// Records the outcome of an external verifier call (Discord/GitHub/Twitter).
+pub fn record_verifier_call(handler: &str, success: bool) {
+    let outcome = if success { "success" } else { "failure" };
+    metrics::counter!(
+        "verifier_calls_total",
+        1,
+        "handler" => handler.to_owned(),
+        "outcome" => outcome.to_owned()
+    );
+}
+
+/// Records an aggregate-call error from the Starknet RPC multicall used to
+/// read on-chain verifier data.
+pub fn record_rpc_aggregate_error() {
+    metrics::counter!("rpc_aggregate_errors_total", 1);
+}
+
+/// Records how long a MongoDB aggregation pipeline took to run, labelled by
+/// the handler that issued it (e.g. `get_non_subscribed_domains`).
+pub fn record_mongo_aggregation_duration(handler: &str, duration_seconds: f64) {
+    metrics::histogram!(
+        "mongo_aggregation_duration_seconds",
+        duration_seconds,
+        "handler" => handler.to_owned()
+    );
+}
@@ -1,11 +1,26 @@
+use metrics_exporter_prometheus::PrometheusHandle;
 use mongodb::Database;
+use reqwest::Client;
+use tokio::sync::broadcast;
 
+use crate::cache::LookupCache;
 use crate::config::Config;
+use crate::endpoints::crosschain::ethereum::record_handlers::HandlerRegistry;
+use crate::endpoints::renewal::subscribe::RenewalNotification;
 use serde::Serialize;
 
 pub struct AppState {
     pub conf: Config,
     pub db: Database,
+    pub verifier_cache: LookupCache,
+    /// Shared HTTP client used by every verifier handler; see `http_client`.
+    pub http_client: Client,
+    /// Renders the current Prometheus snapshot for the `/metrics` endpoint.
+    pub metrics_handle: PrometheusHandle,
+    /// Fans renewal/expiry state changes out to `/renewal/subscribe` clients.
+    pub renewal_events: broadcast::Sender<RenewalNotification>,
+    /// Resolves `EvmRecordVerifier::handler` names to `RecordHandler` impls.
+    pub handler_registry: HandlerRegistry,
 }
 
 #[derive(Serialize)]